@@ -0,0 +1,70 @@
+//! A contention-free counter for the hot "just bump a shared number" path.
+//!
+//! `shared::SharedCell<T>` and `shared::sync::SharedCell<T>` both funnel
+//! every mutation through a `RefCell`/`RwLock` borrow. For a plain
+//! counter that's more synchronization than necessary: `AtomicCounter`
+//! stores its value in a `std::sync::atomic::AtomicUsize` and updates it
+//! with `fetch_add`, so many `Arc`-sharing threads can increment it
+//! without ever taking a lock.
+//!
+//! `Ordering::Relaxed` is used for the bump itself: a pure counter
+//! publishes no other memory through its value, so there's nothing for a
+//! stronger ordering to protect. `get()` loads with `Ordering::Acquire`
+//! instead, which is the right choice the moment a reader needs to
+//! observe writes to *other* memory that happened-before the increment
+//! it's reading (the `Acquire`/`Release` pairing) — `Relaxed` alone would
+//! not guarantee that.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A counter that can be incremented and read without locking.
+#[derive(Debug, Default)]
+pub struct AtomicCounter {
+    value: AtomicUsize,
+}
+
+impl AtomicCounter {
+    pub fn new(initial_value: usize) -> Self {
+        AtomicCounter {
+            value: AtomicUsize::new(initial_value),
+        }
+    }
+
+    /// Bumps the counter by one and returns the previous value.
+    pub fn increment(&self) -> usize {
+        self.value.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Reads the current value.
+    pub fn get(&self) -> usize {
+        self.value.load(Ordering::Acquire)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn concurrent_increments_are_not_lost() {
+        let counter = Arc::new(AtomicCounter::new(0));
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        counter.increment();
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().expect("counter thread panicked");
+        }
+
+        assert_eq!(counter.get(), 8000);
+    }
+}
@@ -1,92 +1,149 @@
-use std::rc::Rc;
-use std::cell::RefCell;
-
-// Our simpler data structure to be shared and mutated
-struct SharedData {
-    id: String,
-    value: i32, // A mutable piece of data
-}
-
-impl SharedData {
-    fn new(id: &str, initial_value: i32) -> Self {
-        SharedData {
-            id: id.to_string(),
-            value: initial_value,
-        }
-    }
-
-    fn update_value(&mut self, new_value: i32) {
-        println!("Updating value for '{}' from {} to {}", self.id, self.value, new_value);
-        self.value = new_value;
-    }
+mod atomic;
+mod observer;
+mod shared;
+mod tracked_rc;
 
-    fn increment_value(&mut self) {
-        println!("Incrementing value for '{}' from {} to {}", self.id, self.value, self.value + 1);
-        self.value += 1;
-    }
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::thread;
 
-    fn display(&self) {
-        println!("Data ID: {}, Current Value: {}", self.id, self.value);
-    }
-}
+use atomic::AtomicCounter;
+use observer::LoggingObserver;
+use shared::{increment_value, update_value, SharedCell, SharedData};
+use tracked_rc::TrackedRc;
 
 fn main() {
     // Main Moment 1: Initial setup.
-    // Create an instance of SharedData wrapped in RefCell (for interior mutability)
-    // and then wrapped in Rc (for shared ownership).
-    let shared_item = Rc::new(RefCell::new(SharedData::new("ConfigItem", 10)));
+    // Create a generic SharedData<i32> wrapped in a SharedCell (Rc<RefCell<...>>
+    // under the hood), so the demo below no longer cares that the payload
+    // happens to be an integer.
+    let shared_item = SharedCell::new(SharedData::new("ConfigItem", 10));
 
     // Main Moment 2: Observing initial state.
     // Print the initial state of the shared data and its reference count.
-    // .borrow() is used for immutable access to the data inside RefCell.
     println!("--- Initial State ---");
-    println!("Initial strong count: {}", Rc::strong_count(&shared_item));
-    shared_item.borrow().display();
+    println!("Tracking shared data with id '{}'", shared_item.read().id());
+    println!("Initial value via accessor: {:?}", shared_item.read().value());
+    println!("Initial strong count: {}", shared_item.strong_count());
+    shared_item.read().display();
 
     // Main Moment 3: Component A gets shared access.
-    // Clone the Rc to give Component A shared ownership.
+    // Clone the SharedCell to give Component A shared ownership.
     // The strong count increases. Component A reads the current state.
     println!("\n--- Component A (gets read access) ---");
-    let component_a_ref = Rc::clone(&shared_item);
-    println!("Strong count after Component A clone: {}", Rc::strong_count(&shared_item));
-    component_a_ref.borrow().display();
+    let component_a_ref = shared_item.clone();
+    println!(
+        "Strong count after Component A clone: {}",
+        shared_item.strong_count()
+    );
+    component_a_ref.read().display();
 
     // Main Moment 4: Component B gets shared access.
-    // Clone the Rc again for Component B.
+    // Clone the SharedCell again for Component B.
     // The strong count increases further.
     println!("\n--- Component B (gets write access and modifies) ---");
-    let component_b_ref = Rc::clone(&shared_item);
-    println!("Strong count after Component B clone: {}", Rc::strong_count(&shared_item));
+    let component_b_ref = shared_item.clone();
+    println!(
+        "Strong count after Component B clone: {}",
+        shared_item.strong_count()
+    );
+
+    // Main Moment 4.5: An observer subscribes to changes.
+    // The observer is kept alive by its own Rc; SharedData stores only a
+    // Weak handle, so there's no reference cycle between subject and
+    // subscriber.
+    let logger: Rc<RefCell<dyn observer::Observer<i32>>> =
+        Rc::new(RefCell::new(LoggingObserver::new("AuditLog")));
+    component_b_ref.with_mut(|data| data.subscribe(&logger));
 
     // Main Moment 5: Component B modifies the shared data.
-    // .borrow_mut() is used for mutable access. This call will panic if
-    // borrowing rules are violated (e.g., another mutable borrow is active).
-    component_b_ref.borrow_mut().update_value(25);
-    component_b_ref.borrow_mut().increment_value(); // Value becomes 26
+    // with_mut() borrows mutably for the duration of the closure. This call
+    // will panic if borrowing rules are violated (e.g., another mutable
+    // borrow is active).
+    component_b_ref.with_mut(|data| update_value(data, 25));
+    component_b_ref.with_mut(increment_value); // Value becomes 26
 
     // Main Moment 6: Component B confirms its modifications.
     // Display the data from Component B's perspective.
     println!("Component B finished modifications.");
-    component_b_ref.borrow().display();
+    component_b_ref.read().display();
 
     // Main Moment 7: Component A observes the changes.
     // Component A's reference now sees the data modified by Component B,
     // demonstrating that they share the same underlying data.
     println!("\n--- Component A (reads again) ---");
     println!("Component A sees updated data:");
-    component_a_ref.borrow().display();
+    component_a_ref.read().display();
 
     // Main Moment 8: Original reference also observes changes.
     // The original 'shared_item' reference also sees the updated data.
-    // Display the final state and reference count before any Rc instances are dropped.
+    // Display the final state and reference count before any handles are dropped.
     println!("\n--- Original Reference (reads again) ---");
     println!("Main's 'shared_item' sees updated data:");
-    shared_item.borrow().display();
-    println!("Final strong count before drops: {}", Rc::strong_count(&shared_item));
+    shared_item.read().display();
+    println!(
+        "Final strong count before drops: {}",
+        shared_item.strong_count()
+    );
+
+    // Main Moment 8.5: Undo the last write.
+    // Every update/increment already funnels through a single choke point,
+    // so the history log needed nothing more than a push at that point.
+    // undo() pops it and restores the previous value.
+    println!("\n--- Undo Last Change ---");
+    println!(
+        "History before undo: {:?}",
+        shared_item.with_mut(|data| data.history().to_vec())
+    );
+    shared_item.with_mut(|data| data.undo());
+    shared_item.read().display();
 
     // Main Moment 9: Automatic cleanup.
     // As component_a_ref, component_b_ref, and shared_item go out of scope at the end of main,
-    // their destructors are called. The Rc strong count decreases for each.
+    // their destructors are called. The strong count decreases for each.
     // When the strong count reaches zero, the RefCell and the SharedData it contains are dropped,
     // freeing the memory. This happens automatically.
-}
\ No newline at end of file
+
+    // Main Moment 10: Same scenario, across real threads.
+    // `RefCell` is not `Sync`, so it cannot cross thread boundaries. The
+    // `shared::sync` module mirrors everything above with `Arc<RwLock<T>>`
+    // instead, which is safe to share between spawned threads.
+    println!("\n--- Parallel Mode (Arc<RwLock<T>>, real threads) ---");
+    shared::sync::run_demo(3);
+
+    // Main Moment 11: Lock-free increment for the hot path.
+    // When the shared state really is "just a number", AtomicCounter lets
+    // every thread bump it with fetch_add instead of taking a RefCell/RwLock
+    // borrow at all.
+    println!("\n--- Lock-Free Mode (Arc<AtomicCounter>) ---");
+    let counter = Arc::new(AtomicCounter::new(0));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    counter.increment();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("counter thread panicked");
+    }
+    println!("Final atomic counter value: {}", counter.get());
+
+    // Main Moment 12: TrackedRc — the same Rc<RefCell<T>> combo, but
+    // observable. *handle still works via the Ref/RefMut-backed guards
+    // returned by borrow()/borrow_mut(), while every borrow is counted and
+    // the final release is logged automatically instead of by hand.
+    println!("\n--- Tracked Smart Pointer (TrackedRc<T>) ---");
+    let tracked = TrackedRc::new("TrackedConfig", SharedData::new("TrackedConfig", 1));
+    let tracked_clone = tracked.clone();
+    println!("TrackedRc strong count: {}", tracked.strong_count());
+    tracked.borrow().display();
+    tracked_clone.borrow_mut().value();
+    println!("Borrow stats so far: {:?}", tracked.borrow_stats());
+    drop(tracked_clone);
+    drop(tracked);
+}
@@ -0,0 +1,38 @@
+//! The subscriber half of a small publish/subscribe system built on top
+//! of `shared::SharedData`.
+//!
+//! Observers register with a strong `Rc` they keep ownership of, but
+//! `SharedData` stores only `Weak` handles to them (see
+//! [`crate::shared::SharedData::subscribe`]), so the subject never keeps a
+//! subscriber alive and no `Rc` reference cycle forms. This is the same
+//! technique used for DAG-style links where a node is pointed at by
+//! several others without owning any of them.
+
+use std::fmt::Debug;
+
+/// Something that wants to be told when a `SharedData<T>` value changes.
+pub trait Observer<T> {
+    fn on_changed(&self, old: &T, new: &T);
+}
+
+/// A simple observer used by the demo: logs every change it is notified of.
+pub struct LoggingObserver {
+    name: String,
+}
+
+impl LoggingObserver {
+    pub fn new(name: &str) -> Self {
+        LoggingObserver {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl<T: Debug> Observer<T> for LoggingObserver {
+    fn on_changed(&self, old: &T, new: &T) {
+        println!(
+            "[observer:{}] value changed from {:?} to {:?}",
+            self.name, old, new
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! A small, reusable "shared and mutable" building block.
+//!
+//! `SharedCell<T>` wraps `Rc<RefCell<T>>` so several owners can hold a
+//! clone of the same handle, read the value through `read()`, and mutate
+//! it through the single choke point `with_mut()`. `SharedData<T>` is the
+//! payload used by the example in `main`, kept generic so the demo isn't
+//! tied to `i32` anymore. `SharedData` also doubles as the publisher side
+//! of a small publish/subscribe system; see [`crate::observer`].
+
+pub mod sync;
+
+use std::cell::{Ref, RefCell};
+use std::fmt::Debug;
+use std::ops::AddAssign;
+use std::rc::{Rc, Weak};
+
+use crate::observer::Observer;
+
+/// A piece of data identified by `id`, shared and mutated through a
+/// [`SharedCell`].
+///
+/// Holds only `Weak` references to its subscribers: `SharedData` never
+/// keeps an observer alive, so subscribing does not create an `Rc`
+/// reference cycle between subject and observer.
+pub struct SharedData<T> {
+    id: String,
+    value: T,
+    subscribers: Vec<Weak<RefCell<dyn Observer<T>>>>,
+    history: Vec<T>,
+}
+
+impl<T> SharedData<T> {
+    pub fn new(id: &str, initial_value: T) -> Self {
+        SharedData {
+            id: id.to_string(),
+            value: initial_value,
+            subscribers: Vec::new(),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Registers `observer` to be notified on future changes.
+    ///
+    /// The caller keeps `observer` alive (typically via an `Rc` it holds
+    /// onto); `SharedData` stores only a `Weak` handle to it.
+    pub fn subscribe(&mut self, observer: &Rc<RefCell<dyn Observer<T>>>) {
+        self.subscribers.push(Rc::downgrade(observer));
+    }
+
+    /// Notifies every live subscriber of a change, pruning any whose
+    /// `Rc` has since been dropped.
+    fn notify(&mut self, old: &T, new: &T) {
+        self.subscribers.retain(|weak| match weak.upgrade() {
+            Some(observer) => {
+                observer.borrow().on_changed(old, new);
+                true
+            }
+            None => false,
+        });
+    }
+
+    /// Records `old` as the value to restore on the next [`undo`](Self::undo).
+    ///
+    /// `update_value`/`increment_value` already funnel every write through
+    /// this one choke point, so the history log falls out of a single call
+    /// here rather than needing to be re-wired into every caller.
+    fn record(&mut self, old: T) {
+        self.history.push(old);
+    }
+
+    /// Restores the value as of the last recorded mutation, if any.
+    ///
+    /// Returns `true` if a previous state was restored, `false` if the
+    /// history is empty.
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(previous) => {
+                self.value = previous;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The sequence of previous values, oldest first, available to [`undo`](Self::undo).
+    pub fn history(&self) -> &[T] {
+        &self.history
+    }
+}
+
+impl<T: Debug> SharedData<T> {
+    pub fn display(&self) {
+        println!("Data ID: {}, Current Value: {:?}", self.id, self.value);
+    }
+}
+
+/// Replaces the value held by `data`, logging the transition and
+/// notifying subscribers.
+pub fn update_value<T: Debug + Clone>(data: &mut SharedData<T>, new_value: T) {
+    let old = data.value.clone();
+    println!(
+        "Updating value for '{}' from {:?} to {:?}",
+        data.id, old, new_value
+    );
+    data.value = new_value.clone();
+    data.notify(&old, &new_value);
+    data.record(old);
+}
+
+/// Bumps the value held by `data` by one, logging the transition and
+/// notifying subscribers.
+pub fn increment_value<T>(data: &mut SharedData<T>)
+where
+    T: AddAssign + From<u8> + Copy + Debug,
+{
+    let old = data.value;
+    data.value += T::from(1);
+    let new = data.value;
+    println!(
+        "Incrementing value for '{}' from {:?} to {:?}",
+        data.id, old, new
+    );
+    data.notify(&old, &new);
+    data.record(old);
+}
+
+/// A typed handle around `Rc<RefCell<T>>`.
+///
+/// Clone it to share ownership; every clone observes the same underlying
+/// value. `SharedCell` places no bound on `T`: reading and mutating work for
+/// any `T`. Only the `display` convenience method on [`SharedData`] needs
+/// `T: Debug`.
+pub struct SharedCell<T> {
+    inner: Rc<RefCell<T>>,
+}
+
+// Hand-written instead of `#[derive(Clone)]`: the derive would add a
+// `T: Clone` bound to the generated impl, but `Rc<RefCell<T>>` is `Clone`
+// for any `T` — cloning a handle never needs to clone the payload itself.
+impl<T> Clone for SharedCell<T> {
+    fn clone(&self) -> Self {
+        SharedCell {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> SharedCell<T> {
+    pub fn new(value: T) -> Self {
+        SharedCell {
+            inner: Rc::new(RefCell::new(value)),
+        }
+    }
+
+    /// Borrows the value immutably.
+    pub fn read(&self) -> Ref<'_, T> {
+        self.inner.borrow()
+    }
+
+    /// Borrows the value mutably for the duration of `f` and returns its result.
+    pub fn with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut self.inner.borrow_mut())
+    }
+
+    /// Number of `SharedCell` handles currently sharing this value.
+    pub fn strong_count(&self) -> usize {
+        Rc::strong_count(&self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct CountingObserver {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl Observer<i32> for CountingObserver {
+        fn on_changed(&self, _old: &i32, _new: &i32) {
+            self.calls.set(self.calls.get() + 1);
+        }
+    }
+
+    #[test]
+    fn notify_prunes_subscribers_once_their_rc_is_dropped() {
+        let mut data = SharedData::new("Obs", 0);
+        let calls = Rc::new(Cell::new(0));
+        let observer: Rc<RefCell<dyn Observer<i32>>> = Rc::new(RefCell::new(CountingObserver {
+            calls: Rc::clone(&calls),
+        }));
+        data.subscribe(&observer);
+
+        update_value(&mut data, 1);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(data.subscribers.len(), 1);
+
+        drop(observer);
+        update_value(&mut data, 2);
+        assert_eq!(calls.get(), 1, "dropped observer must not be notified again");
+        assert_eq!(data.subscribers.len(), 0, "dead Weak entry must be pruned");
+    }
+
+    #[test]
+    fn undo_restores_the_previous_value() {
+        let mut data = SharedData::new("Config", 10);
+        update_value(&mut data, 25);
+        increment_value(&mut data); // 26
+        assert_eq!(data.history(), &[10, 25]);
+
+        assert!(data.undo());
+        assert_eq!(*data.value(), 25);
+        assert!(data.undo());
+        assert_eq!(*data.value(), 10);
+        assert!(!data.undo(), "undo on an empty history must report false");
+        assert_eq!(*data.value(), 10);
+    }
+
+    #[test]
+    fn clone_shares_the_same_value_without_requiring_t_clone() {
+        // SharedData intentionally does not implement Clone; this only
+        // compiles (and passes) because SharedCell::clone is hand-written
+        // rather than derived.
+        let cell = SharedCell::new(SharedData::new("Config", 10));
+        let handle = cell.clone();
+        assert_eq!(cell.strong_count(), 2);
+
+        handle.with_mut(|data| update_value(data, 25));
+
+        assert_eq!(*cell.read().value(), 25);
+        assert_eq!(*handle.read().value(), 25);
+    }
+}
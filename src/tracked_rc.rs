@@ -0,0 +1,163 @@
+//! An `Rc<RefCell<T>>` wrapper that doubles as a custom smart pointer.
+//!
+//! This applies the `Deref`/`Drop` pattern straight from the smart-pointer
+//! docs: a struct becomes a smart pointer precisely by implementing those
+//! two traits. `TrackedRc<T>::borrow`/`borrow_mut` return guards
+//! (`TrackedRef`/`TrackedRefMut`) that wrap the real `Ref`/`RefMut`
+//! returned by the inner `RefCell`, so `*handle` still works exactly as
+//! it does with `Ref`/`RefMut` directly — but every borrow taken through
+//! a `TrackedRc` is counted, and the custom `Drop` logs when the last
+//! owner releases the value, turning the `println!`-heavy lifecycle
+//! narration the original demo did by hand into something observable
+//! after the fact.
+
+use std::cell::{Ref, RefCell, RefMut};
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Default)]
+struct BorrowStats {
+    immutable: AtomicUsize,
+    mutable: AtomicUsize,
+}
+
+/// A guard returned by [`TrackedRc::borrow`]; derefs to `&T`.
+pub struct TrackedRef<'a, T> {
+    guard: Ref<'a, T>,
+}
+
+impl<'a, T> Deref for TrackedRef<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+/// A guard returned by [`TrackedRc::borrow_mut`]; derefs to `&T`/`&mut T`.
+pub struct TrackedRefMut<'a, T> {
+    guard: RefMut<'a, T>,
+}
+
+impl<'a, T> Deref for TrackedRefMut<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<'a, T> DerefMut for TrackedRefMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+/// An `Rc<RefCell<T>>` handle that counts the borrows taken through it and
+/// logs when the last owner is dropped.
+///
+/// Cloning a `TrackedRc` shares both the underlying value and its borrow
+/// counters, mirroring `Rc`'s own sharing semantics.
+pub struct TrackedRc<T> {
+    label: String,
+    inner: Rc<RefCell<T>>,
+    stats: Rc<BorrowStats>,
+}
+
+impl<T> TrackedRc<T> {
+    pub fn new(label: &str, value: T) -> Self {
+        TrackedRc {
+            label: label.to_string(),
+            inner: Rc::new(RefCell::new(value)),
+            stats: Rc::new(BorrowStats::default()),
+        }
+    }
+
+    /// Borrows the value immutably, counting the borrow. Panics exactly
+    /// when the underlying `RefCell::borrow` would.
+    pub fn borrow(&self) -> TrackedRef<'_, T> {
+        self.stats.immutable.fetch_add(1, Ordering::Relaxed);
+        TrackedRef {
+            guard: self.inner.borrow(),
+        }
+    }
+
+    /// Borrows the value mutably, counting the borrow. Panics exactly
+    /// when the underlying `RefCell::borrow_mut` would (e.g. an
+    /// overlapping live borrow) — `borrow_stats()` can then be used to
+    /// diagnose how many borrows preceded the panic. The borrow is counted
+    /// before it is attempted, so the count includes the one that panicked.
+    pub fn borrow_mut(&self) -> TrackedRefMut<'_, T> {
+        self.stats.mutable.fetch_add(1, Ordering::Relaxed);
+        TrackedRefMut {
+            guard: self.inner.borrow_mut(),
+        }
+    }
+
+    /// Total `(immutable, mutable)` borrows requested through this handle or
+    /// any of its clones, since creation, including any that panicked.
+    pub fn borrow_stats(&self) -> (usize, usize) {
+        (
+            self.stats.immutable.load(Ordering::Relaxed),
+            self.stats.mutable.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Number of `TrackedRc` handles currently sharing this value.
+    pub fn strong_count(&self) -> usize {
+        Rc::strong_count(&self.inner)
+    }
+}
+
+impl<T> Clone for TrackedRc<T> {
+    fn clone(&self) -> Self {
+        TrackedRc {
+            label: self.label.clone(),
+            inner: Rc::clone(&self.inner),
+            stats: Rc::clone(&self.stats),
+        }
+    }
+}
+
+impl<T> Drop for TrackedRc<T> {
+    fn drop(&mut self) {
+        // `self.inner` is still alive at this point (its own Drop runs
+        // after ours), so a strong count of 1 means this is the last
+        // handle and the wrapped value is about to be released.
+        if Rc::strong_count(&self.inner) == 1 {
+            let (immutable, mutable) = self.borrow_stats();
+            println!(
+                "[TrackedRc:{}] last owner dropped, releasing value ({immutable} immutable / {mutable} mutable borrows taken)",
+                self.label
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrow_and_borrow_mut_are_counted_across_clones() {
+        let tracked = TrackedRc::new("Counter", 0i32);
+        let clone = tracked.clone();
+
+        let _ = *tracked.borrow();
+        *clone.borrow_mut() += 1;
+        let _ = *tracked.borrow();
+
+        assert_eq!(tracked.borrow_stats(), (2, 1));
+        assert_eq!(clone.borrow_stats(), (2, 1));
+        assert_eq!(*tracked.borrow(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn overlapping_borrow_mut_still_panics_like_refcell() {
+        let tracked = TrackedRc::new("Counter", 0i32);
+        let _first = tracked.borrow_mut();
+        let _second = tracked.borrow_mut();
+    }
+}